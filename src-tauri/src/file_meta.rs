@@ -0,0 +1,100 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+/// File info surfaced to the frontend for the currently open document.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMeta {
+    pub size: u64,
+    pub created: Option<u64>,
+    pub modified: Option<u64>,
+    pub accessed: Option<u64>,
+    pub is_dir: bool,
+    pub is_file: bool,
+    pub is_symlink: bool,
+    pub permissions: PermissionSummary,
+}
+
+/// Cross-platform permission summary. `mode_octal`/`rwx` are only populated
+/// on Unix, where the underlying `st_mode` triad actually exists.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionSummary {
+    pub readonly: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode_octal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rwx: Option<String>,
+}
+
+/// A structured, serializable error so the frontend can show e.g. "file
+/// moved/deleted" instead of the command panicking.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMetaError {
+    pub message: String,
+}
+
+#[tauri::command]
+pub fn get_file_metadata(path: String) -> Result<FileMeta, FileMetaError> {
+    let metadata = std::fs::symlink_metadata(&path).map_err(|_| FileMetaError {
+        message: format!("file not found: {path}"),
+    })?;
+
+    Ok(FileMeta {
+        size: metadata.len(),
+        created: to_unix_millis(metadata.created()),
+        modified: to_unix_millis(metadata.modified()),
+        accessed: to_unix_millis(metadata.accessed()),
+        is_dir: metadata.is_dir(),
+        is_file: metadata.is_file(),
+        is_symlink: metadata.file_type().is_symlink(),
+        permissions: permission_summary(&metadata),
+    })
+}
+
+fn to_unix_millis(time: std::io::Result<SystemTime>) -> Option<u64> {
+    time.ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+}
+
+#[cfg(unix)]
+fn permission_summary(metadata: &std::fs::Metadata) -> PermissionSummary {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = metadata.permissions().mode();
+    PermissionSummary {
+        readonly: metadata.permissions().readonly(),
+        mode_octal: Some(format!("{:o}", mode & 0o777)),
+        rwx: Some(rwx_triad(mode)),
+    }
+}
+
+#[cfg(unix)]
+fn rwx_triad(mode: u32) -> String {
+    let bit = |shift: u32, ch: char| if mode & (1 << shift) != 0 { ch } else { '-' };
+    [
+        bit(8, 'r'),
+        bit(7, 'w'),
+        bit(6, 'x'),
+        bit(5, 'r'),
+        bit(4, 'w'),
+        bit(3, 'x'),
+        bit(2, 'r'),
+        bit(1, 'w'),
+        bit(0, 'x'),
+    ]
+    .iter()
+    .collect()
+}
+
+#[cfg(windows)]
+fn permission_summary(metadata: &std::fs::Metadata) -> PermissionSummary {
+    PermissionSummary {
+        readonly: metadata.permissions().readonly(),
+        mode_octal: None,
+        rwx: None,
+    }
+}
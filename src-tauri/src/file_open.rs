@@ -0,0 +1,77 @@
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Emitter};
+
+/// Tracks whether the frontend has signalled readiness and buffers any
+/// `open-file` payloads that arrive before that signal so they aren't lost.
+/// Readiness and the pending queue share one mutex so a reader can never
+/// observe "not ready" and then enqueue after the queue has already flushed.
+#[derive(Default)]
+pub struct OpenFileState {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    ready: bool,
+    pending: Vec<String>,
+}
+
+fn is_existing_file(path: &std::path::Path) -> bool {
+    path.exists() && path.is_file()
+}
+
+/// Extracts valid file path(s) passed to this process on the command line.
+pub fn extract_file_args() -> Vec<String> {
+    std::env::args()
+        .skip(1) // Skip exe path
+        .filter(|arg| is_existing_file(std::path::Path::new(arg)))
+        .collect()
+}
+
+/// Extracts valid file path(s) from another instance's argv, resolving
+/// relative paths against that instance's working directory. Used by the
+/// single-instance callback, where `args` and `cwd` describe a second launch
+/// rather than this process.
+pub fn resolve_file_args(args: impl IntoIterator<Item = String>, cwd: &str) -> Vec<String> {
+    let base = std::path::Path::new(cwd);
+    args.into_iter()
+        .skip(1) // Skip exe path
+        .filter_map(|arg| {
+            let candidate = std::path::Path::new(&arg);
+            let resolved = if candidate.is_absolute() {
+                candidate.to_path_buf()
+            } else {
+                base.join(candidate)
+            };
+            is_existing_file(&resolved).then(|| resolved.to_string_lossy().into_owned())
+        })
+        .collect()
+}
+
+/// Emits `open-file` with `paths` if the frontend is ready, otherwise buffers
+/// them until [`mark_frontend_ready`] flushes the queue.
+pub fn emit_open_file(app: &AppHandle, state: &OpenFileState, paths: Vec<String>) {
+    if paths.is_empty() {
+        return;
+    }
+    let mut inner = state.inner.lock().unwrap();
+    if inner.ready {
+        app.emit("open-file", &paths).unwrap();
+    } else {
+        inner.pending.extend(paths);
+    }
+}
+
+/// Marks the frontend as ready and flushes any file paths buffered while it
+/// was starting up.
+pub fn mark_frontend_ready(app: &AppHandle, state: &OpenFileState) {
+    let pending = {
+        let mut inner = state.inner.lock().unwrap();
+        inner.ready = true;
+        std::mem::take(&mut inner.pending)
+    };
+    if !pending.is_empty() {
+        app.emit("open-file", &pending).unwrap();
+    }
+}
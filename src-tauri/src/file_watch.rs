@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+enum PendingKind {
+    Changed,
+    Removed,
+}
+
+struct Pending {
+    last_event: Instant,
+    kind: PendingKind,
+}
+
+/// A live watch on one opened file, plus the debounce bookkeeping used to
+/// coalesce bursts of modify/rename/remove notifications.
+struct WatchHandle {
+    _watcher: RecommendedWatcher,
+    pending: Arc<Mutex<Option<Pending>>>,
+}
+
+/// Holds the active watchers, keyed by watched path. An entry is dropped by
+/// [`unwatch`] or once its file is removed; opening another set of files
+/// adds watches and does not drop the existing ones.
+#[derive(Default)]
+pub struct FileWatchState {
+    watchers: Mutex<HashMap<String, WatchHandle>>,
+}
+
+/// Starts watching `path`, debouncing bursts within [`DEBOUNCE_WINDOW`]
+/// before emitting `file-changed` on modify or `file-removed` on
+/// remove/rename-away. A no-op if `path` is already watched.
+pub fn watch(app: &AppHandle, state: &FileWatchState, path: String) {
+    let mut watchers = state.watchers.lock().unwrap();
+    if watchers.contains_key(&path) {
+        return;
+    }
+
+    let pending: Arc<Mutex<Option<Pending>>> = Arc::new(Mutex::new(None));
+
+    let event_pending = pending.clone();
+    let watched_path = path.clone();
+    let watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        let Ok(event) = res else {
+            return;
+        };
+        let kind = match event.kind {
+            EventKind::Remove(_) => PendingKind::Removed,
+            // Renames arrive as Modify(Name); if the watched path is gone the
+            // file was renamed away rather than replaced.
+            EventKind::Modify(ModifyKind::Name(_)) if !Path::new(&watched_path).exists() => {
+                PendingKind::Removed
+            }
+            EventKind::Modify(_) | EventKind::Create(_) => PendingKind::Changed,
+            _ => return,
+        };
+        *event_pending.lock().unwrap() = Some(Pending {
+            last_event: Instant::now(),
+            kind,
+        });
+    });
+
+    let mut watcher = match watcher {
+        Ok(watcher) => watcher,
+        Err(_) => return,
+    };
+
+    if watcher
+        .watch(Path::new(&path), RecursiveMode::NonRecursive)
+        .is_err()
+    {
+        return;
+    }
+
+    spawn_debounce_thread(app.clone(), path.clone(), pending.clone());
+    watchers.insert(path, WatchHandle { _watcher: watcher, pending });
+}
+
+/// Stops watching `path`, dropping its watcher and debounce thread.
+pub fn unwatch(state: &FileWatchState, path: &str) {
+    state.watchers.lock().unwrap().remove(path);
+}
+
+fn spawn_debounce_thread(app: AppHandle, path: String, pending: Arc<Mutex<Option<Pending>>>) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        // Once `unwatch` drops the map entry, this thread holds
+        // the only remaining clone; stop polling a watch nobody owns anymore.
+        if Arc::strong_count(&pending) == 1 {
+            return;
+        }
+
+        let due = {
+            let mut guard = pending.lock().unwrap();
+            match guard.as_ref() {
+                Some(p) if p.last_event.elapsed() >= DEBOUNCE_WINDOW => guard.take(),
+                _ => None,
+            }
+        };
+
+        match due {
+            Some(Pending { kind: PendingKind::Changed, .. }) => {
+                let _ = app.emit("file-changed", &path);
+            }
+            Some(Pending { kind: PendingKind::Removed, .. }) => {
+                let _ = app.emit("file-removed", &path);
+                // Drop our own entry so a later watch() for this path (e.g.
+                // the file reappearing) isn't blocked by the stale guard.
+                if let Some(state) = app.try_state::<FileWatchState>() {
+                    state.watchers.lock().unwrap().remove(&path);
+                }
+                return;
+            }
+            None => {}
+        }
+    });
+}
+
+#[tauri::command]
+pub fn watch_file(
+    app: AppHandle,
+    watch_state: State<FileWatchState>,
+    recent_state: State<crate::recent_files::RecentFilesState>,
+    path: String,
+) {
+    watch(&app, &watch_state, path.clone());
+    // The dialog plugin opens files straight into the frontend, bypassing
+    // our CLI/Finder/single-instance pipeline, so record it here instead.
+    crate::recent_files::record(&app, &recent_state, &[path]);
+}
+
+#[tauri::command]
+pub fn unwatch_file(state: State<FileWatchState>, path: String) {
+    unwatch(&state, &path);
+}
@@ -1,32 +1,102 @@
+mod file_meta;
+mod file_open;
+mod file_watch;
+mod recent_files;
+mod supported_formats;
+
+use file_meta::get_file_metadata;
+use file_open::{emit_open_file, extract_file_args, mark_frontend_ready, OpenFileState};
+use file_watch::{unwatch_file, watch_file, FileWatchState};
+use recent_files::{clear_recent_files, get_recent_files, RecentFilesState};
+use supported_formats::get_supported_extensions;
+use tauri::AppHandle;
+
+/// Splits `files` into supported/unsupported, then emits the `open-file`
+/// payload, starts watching, and records recents for the supported ones
+/// while reporting the rest on `unsupported-file`. Keeps all subsystems in
+/// sync for every file-open source (CLI args, Finder events, single-instance
+/// forwarding).
+fn open_watch_and_record(
+    app: &AppHandle,
+    open_state: &OpenFileState,
+    watch_state: &FileWatchState,
+    recent_state: &RecentFilesState,
+    files: Vec<String>,
+) {
+    let (accepted, rejected) = supported_formats::partition_by_extension(files);
+    supported_formats::emit_unsupported(app, &rejected);
+
+    for file in &accepted {
+        file_watch::watch(app, watch_state, file.clone());
+    }
+    recent_files::record(app, recent_state, &accepted);
+    emit_open_file(app, open_state, accepted);
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    use tauri::{Emitter, Listener};
-    // Helper to extract valid file path(s) from CLI args
-    fn extract_file_args() -> Vec<String> {
-        std::env::args()
-            .skip(1) // Skip exe path
-            .filter(|arg| {
-                let path = std::path::Path::new(arg);
-                path.exists() && path.is_file()
-            })
-            .collect()
-    }
-    tauri::Builder::default()
+    use tauri::{Listener, Manager, RunEvent};
+
+    let app = tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+            // A second launch's files get forwarded here instead of opening
+            // a second window; focus the existing one and feed it the paths.
+            let files = file_open::resolve_file_args(args, &cwd);
+            let open_state = app.state::<OpenFileState>();
+            let watch_state = app.state::<FileWatchState>();
+            let recent_state = app.state::<RecentFilesState>();
+            open_watch_and_record(app, &open_state, &watch_state, &recent_state, files);
+
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.unminimize();
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }))
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
+        .manage(OpenFileState::default())
+        .manage(FileWatchState::default())
+        .manage(RecentFilesState::default())
+        .invoke_handler(tauri::generate_handler![
+            get_file_metadata,
+            watch_file,
+            unwatch_file,
+            get_recent_files,
+            clear_recent_files,
+            get_supported_extensions
+        ])
         .setup(|app| {
             let files = extract_file_args();
-            if !files.is_empty() {
-                let app_handle = app.handle().clone();
-                let event_handle = app_handle.clone();
-                // Listen for a signal from the frontend that it is ready.
-                app_handle.listen("frontend-ready", move |_event| {
-                    // Once the frontend is ready, emit the event with the file paths.
-                    event_handle.emit("open-file", &files).unwrap();
-                });
-            }
+            let open_state = app.state::<OpenFileState>();
+            let watch_state = app.state::<FileWatchState>();
+            let recent_state = app.state::<RecentFilesState>();
+            open_watch_and_record(app.handle(), &open_state, &watch_state, &recent_state, files);
+
+            let app_handle = app.handle().clone();
+            app.listen("frontend-ready", move |_event| {
+                let state = app_handle.state::<OpenFileState>();
+                mark_frontend_ready(&app_handle, &state);
+            });
+
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application");
+
+    app.run(|app_handle, event| {
+        // Finder delivers opened/dragged documents as an Apple Event after
+        // launch (or on re-open while already running) rather than as argv.
+        if let RunEvent::Opened { urls } = event {
+            let files: Vec<String> = urls
+                .into_iter()
+                .filter_map(|url| url.to_file_path().ok())
+                .map(|path| path.to_string_lossy().into_owned())
+                .collect();
+            let open_state = app_handle.state::<OpenFileState>();
+            let watch_state = app_handle.state::<FileWatchState>();
+            let recent_state = app_handle.state::<RecentFilesState>();
+            open_watch_and_record(app_handle, &open_state, &watch_state, &recent_state, files);
+        }
+    });
 }
@@ -0,0 +1,88 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Emitter, Manager, State};
+
+const MAX_RECENT_FILES: usize = 15;
+const STORE_FILE_NAME: &str = "recent-files.json";
+
+/// In-memory recent-files list, most-recent-first, mirrored to a JSON file
+/// under the app's data dir so it survives across launches.
+#[derive(Default)]
+pub struct RecentFilesState {
+    files: Mutex<Vec<String>>,
+}
+
+fn store_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|dir| dir.join(STORE_FILE_NAME))
+}
+
+fn read_from_disk(app: &AppHandle) -> Vec<String> {
+    let Some(path) = store_path(app) else {
+        return Vec::new();
+    };
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn write_to_disk(app: &AppHandle, files: &[String]) {
+    let Some(path) = store_path(app) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(files) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Loads the persisted list into memory on first use, pruning entries whose
+/// paths no longer exist.
+fn ensure_loaded(app: &AppHandle, files: &mut Vec<String>) {
+    if files.is_empty() {
+        *files = read_from_disk(app)
+            .into_iter()
+            .filter(|path| std::path::Path::new(path).exists())
+            .collect();
+    }
+}
+
+/// Records newly opened `paths` at the front of the list, de-duplicated and
+/// capped at [`MAX_RECENT_FILES`], persists it, and emits
+/// `recent-files-updated`.
+pub fn record(app: &AppHandle, state: &RecentFilesState, paths: &[String]) {
+    if paths.is_empty() {
+        return;
+    }
+
+    let mut files = state.files.lock().unwrap();
+    ensure_loaded(app, &mut files);
+
+    for path in paths.iter().rev() {
+        files.retain(|existing| existing != path);
+        files.insert(0, path.clone());
+    }
+    files.truncate(MAX_RECENT_FILES);
+
+    write_to_disk(app, &files);
+    let _ = app.emit("recent-files-updated", &*files);
+}
+
+#[tauri::command]
+pub fn get_recent_files(app: AppHandle, state: State<RecentFilesState>) -> Vec<String> {
+    let mut files = state.files.lock().unwrap();
+    ensure_loaded(&app, &mut files);
+    files.clone()
+}
+
+#[tauri::command]
+pub fn clear_recent_files(app: AppHandle, state: State<RecentFilesState>) {
+    let mut files = state.files.lock().unwrap();
+    files.clear();
+    write_to_disk(&app, &files);
+    let _ = app.emit("recent-files-updated", &*files);
+}
@@ -0,0 +1,55 @@
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+/// Single source of truth for which file extensions this viewer can open.
+/// Also exposed to the frontend via [`get_supported_extensions`] so it can
+/// configure the dialog plugin's filters from the same list.
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["msh3"];
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct UnsupportedFile {
+    path: String,
+    extension: String,
+}
+
+/// Splits `paths` into those with a supported extension and those without,
+/// comparing extensions case-insensitively.
+pub fn partition_by_extension(paths: Vec<String>) -> (Vec<String>, Vec<(String, String)>) {
+    let mut accepted = Vec::new();
+    let mut rejected = Vec::new();
+
+    for path in paths {
+        let extension = std::path::Path::new(&path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        if SUPPORTED_EXTENSIONS.contains(&extension.as_str()) {
+            accepted.push(path);
+        } else {
+            rejected.push((path, extension));
+        }
+    }
+
+    (accepted, rejected)
+}
+
+/// Emits `unsupported-file` for each rejected `(path, extension)` pair so
+/// the frontend can surface a clear message instead of silently ignoring it.
+pub fn emit_unsupported(app: &AppHandle, rejected: &[(String, String)]) {
+    for (path, extension) in rejected {
+        let _ = app.emit(
+            "unsupported-file",
+            UnsupportedFile {
+                path: path.clone(),
+                extension: extension.clone(),
+            },
+        );
+    }
+}
+
+#[tauri::command]
+pub fn get_supported_extensions() -> Vec<&'static str> {
+    SUPPORTED_EXTENSIONS.to_vec()
+}